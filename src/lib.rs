@@ -4,8 +4,16 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
 //! Simple bitset library like C++.
 
+extern crate alloc;
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+#[derive(Clone)]
 pub struct BitSet {
     bits:   Vec<u64>,
     nbits: usize,
@@ -32,6 +40,72 @@ impl BitSet {
             self.nbits / 64 + 1
         }
     }
+
+    // Clear any bits in the final block that live beyond `nbits`, so that
+    // `count()`/`any()` never see garbage bits left over by a word-at-a-time op.
+    fn mask_tail(&mut self) {
+        let rem = self.nbits % 64;
+        if rem != 0 {
+            if let Some(last) = self.bits.last_mut() {
+                *last &= (1u64 << rem) - 1;
+            }
+        }
+    }
+
+    // Grow `self` so that `bit_idx` is a valid index, pushing zero words
+    // as needed and extending `nbits` to cover it.
+    fn ensure_capacity(&mut self, bit_idx: usize) {
+        if bit_idx >= self.nbits {
+            let block_idx = bit_idx / 64;
+            if block_idx >= self.bits.len() {
+                self.bits.resize(block_idx + 1, 0);
+            }
+            self.nbits = bit_idx + 1;
+        }
+    }
+}
+
+/// Iterator over the indices of the set bits in a `BitSet`, returned by
+/// [`BitSet::iter`].
+pub struct Iter<'a> {
+    bits: &'a [u64],
+    block_idx: usize,
+    word: u64,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while self.word == 0 {
+            self.block_idx += 1;
+            if self.block_idx >= self.bits.len() {
+                return None;
+            }
+            self.word = self.bits[self.block_idx];
+        }
+        let tz = self.word.trailing_zeros() as usize;
+        let idx = self.block_idx * 64 + tz;
+        self.word &= self.word - 1;
+        Some(idx)
+    }
+}
+
+impl core::iter::FromIterator<usize> for BitSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut bitset = BitSet::new();
+        bitset.extend(iter);
+        bitset
+    }
+}
+
+impl core::iter::Extend<usize> for BitSet {
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+        for bit_idx in iter {
+            self.ensure_capacity(bit_idx);
+            self.set(bit_idx, true);
+        }
+    }
 }
 
 // Public functions
@@ -118,6 +192,40 @@ impl BitSet {
         }
     }
 
+    /// Create a new `BitSet` from a byte slice. Bits are packed MSB-first
+    /// within each byte: bit `0` is the most significant bit of `bytes[0]`,
+    /// bit `7` is its least significant bit, bit `8` is the most significant
+    /// bit of `bytes[1]`, and so on. This is the same convention used by
+    /// [`to_bytes`](BitSet::to_bytes), so `BitSet::from_bytes(&bs.to_bytes())`
+    /// round-trips.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - A byte slice, MSB-first per byte.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitset::BitSet;
+    ///
+    /// let bs = BitSet::from_bytes(&[0b11010000]);
+    /// assert!(bs.test(0) == true);
+    /// assert!(bs.test(1) == true);
+    /// assert!(bs.test(2) == false);
+    /// assert!(bs.test(3) == true);
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let mut bitset = BitSet::with_capacity(bytes.len() * 8);
+        for (byte_idx, &byte) in bytes.iter().enumerate() {
+            for bit_in_byte in 0..8 {
+                if byte & (0x80 >> bit_in_byte) != 0 {
+                    bitset.set(byte_idx * 8 + bit_in_byte, true);
+                }
+            }
+        }
+        bitset
+    }
+
     /// Return the actual bits count.
     ///
     /// # Example
@@ -194,17 +302,375 @@ impl BitSet {
         !self.any()
     }
 
-    // // bit vec operations
-    // fn union(&mut self, vec: &Vec<u64>) {
-    //     //TODO
-    // }
+    /// Return an iterator over the indices of every bit set to `1`, in
+    /// ascending order. Scans one `u64` block at a time, so a full pass
+    /// costs one step per set bit plus one per block, rather than one per
+    /// bit of [`size`](BitSet::size).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitset::BitSet;
+    ///
+    /// let bs = BitSet::from_u64(0b1010);
+    /// let members: Vec<usize> = bs.iter().collect();
+    /// assert!(members == vec![1, 3]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_> {
+        let blocks = self.blocks();
+        Iter {
+            bits: &self.bits[..blocks],
+            block_idx: 0,
+            word: if blocks > 0 { self.bits[0] } else { 0 },
+        }
+    }
+
+    /// Serialize the set bits into a byte vector, using the same MSB-first
+    /// convention as [`from_bytes`](BitSet::from_bytes). The result is
+    /// exactly `(size() + 7) / 8` bytes long.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitset::BitSet;
+    ///
+    /// let bs = BitSet::from_bytes(&[0b11010000]);
+    /// assert!(bs.to_bytes() == vec![0b11010000]);
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let nbytes = (self.nbits + 7) / 8;
+        let mut bytes = vec![0u8; nbytes];
+        for idx in self.iter() {
+            bytes[idx / 8] |= 0x80 >> (idx % 8);
+        }
+        bytes
+    }
+
+    /// Borrow the backing `u64` words directly, one bit per index as used
+    /// throughout this type (bit `0` is the least significant bit of
+    /// `as_slice()[0]`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitset::BitSet;
+    ///
+    /// let bs = BitSet::from_u64(0b101);
+    /// assert!(bs.as_slice() == &[0b101]);
+    /// ```
+    pub fn as_slice(&self) -> &[u64] {
+        &self.bits[..self.blocks()]
+    }
+
+    /// Consume the `BitSet` and recover its backing words as a `Vec<u64>`,
+    /// using the same word layout as [`as_slice`](BitSet::as_slice).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitset::BitSet;
+    ///
+    /// let bs = BitSet::from_u64(0b101);
+    /// assert!(bs.into_vec64() == vec![0b101]);
+    /// ```
+    pub fn into_vec64(mut self) -> Vec<u64> {
+        let blocks = self.blocks();
+        self.bits.truncate(blocks);
+        self.bits
+    }
+
+    // range/bulk operations
+    /// Return the index of the lowest set bit, or `None` if the set is
+    /// empty. Scans whole `u64` words, skipping all-zero ones.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitset::BitSet;
+    ///
+    /// let bs = BitSet::from_u64(0b1000);
+    /// assert!(bs.find_first() == Some(3));
+    /// ```
+    pub fn find_first(&self) -> Option<usize> {
+        for (i, &word) in self.bits[..self.blocks()].iter().enumerate() {
+            if word != 0 {
+                return Some(i * 64 + word.trailing_zeros() as usize);
+            }
+        }
+        None
+    }
+
+    /// Return the index of the lowest set bit at or after `from`, or `None`
+    /// if there isn't one. Scans whole `u64` words, skipping all-zero ones.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitset::BitSet;
+    ///
+    /// let bs = BitSet::from_u64(0b1010);
+    /// assert!(bs.find_next(2) == Some(3));
+    /// assert!(bs.find_next(4) == None);
+    /// ```
+    pub fn find_next(&self, from: usize) -> Option<usize> {
+        if from >= self.nbits {
+            return None;
+        }
+        let start_block = from / 64;
+        let first_word = self.bits[start_block] & (!0u64 << (from % 64));
+        if first_word != 0 {
+            return Some(start_block * 64 + first_word.trailing_zeros() as usize);
+        }
+        for i in (start_block + 1)..self.blocks() {
+            if self.bits[i] != 0 {
+                return Some(i * 64 + self.bits[i].trailing_zeros() as usize);
+            }
+        }
+        None
+    }
 
-    // fn intersect(&mut self, vec: Vec<u64>) {
-    //     //TODO
-    // }
+    /// Count the set bits in `[start, end)`. Full words in the middle of
+    /// the range are popcounted whole; the two boundary words are masked
+    /// down to the part that overlaps the range first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitset::BitSet;
+    ///
+    /// let bs = BitSet::from_u64(0b1111);
+    /// assert!(bs.count_range(1, 3) == 2);
+    /// ```
+    pub fn count_range(&self, start: usize, end: usize) -> u64 {
+        let end = end.min(self.nbits);
+        if start >= end {
+            return 0;
+        }
+        let start_block = start / 64;
+        let end_block = (end - 1) / 64;
+        let hi_bit = (end - 1) % 64;
+        let last_mask = if hi_bit == 63 { !0u64 } else { (1u64 << (hi_bit + 1)) - 1 };
+        if start_block == end_block {
+            let mask = (!0u64 << (start % 64)) & last_mask;
+            return bit_count64(self.bits[start_block] & mask);
+        }
+        let mut total = bit_count64(self.bits[start_block] & (!0u64 << (start % 64)));
+        for i in (start_block + 1)..end_block {
+            total += bit_count64(self.bits[i]);
+        }
+        total + bit_count64(self.bits[end_block] & last_mask)
+    }
+
+    /// Set every bit in `[start, end)` to `v`. Interior words are filled
+    /// directly with `0` or `u64::MAX`; only the two boundary words are
+    /// masked. Grows the `BitSet` first if `v` is `true` and `end` is
+    /// beyond the current [`size`](BitSet::size).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitset::BitSet;
+    ///
+    /// let mut bs = BitSet::with_capacity(8);
+    /// bs.set_range(2, 6, true);
+    /// assert!(bs.count() == 4);
+    /// assert!(bs.test(1) == false);
+    /// assert!(bs.test(6) == false);
+    /// ```
+    pub fn set_range(&mut self, start: usize, end: usize, v: bool) {
+        if start >= end {
+            return;
+        }
+        if v {
+            self.ensure_capacity(end - 1);
+        }
+        let end = end.min(self.nbits);
+        if start >= end {
+            return;
+        }
+        let start_block = start / 64;
+        let end_block = (end - 1) / 64;
+        let hi_bit = (end - 1) % 64;
+        let last_mask = if hi_bit == 63 { !0u64 } else { (1u64 << (hi_bit + 1)) - 1 };
+        if start_block == end_block {
+            let mask = (!0u64 << (start % 64)) & last_mask;
+            if v {
+                self.bits[start_block] |= mask;
+            }
+            else {
+                self.bits[start_block] &= !mask;
+            }
+            return;
+        }
+        let first_mask = !0u64 << (start % 64);
+        if v {
+            self.bits[start_block] |= first_mask;
+        }
+        else {
+            self.bits[start_block] &= !first_mask;
+        }
+        let fill = if v { u64::max_value() } else { 0 };
+        for i in (start_block + 1)..end_block {
+            self.bits[i] = fill;
+        }
+        if v {
+            self.bits[end_block] |= last_mask;
+        }
+        else {
+            self.bits[end_block] &= !last_mask;
+        }
+    }
+
+    // bit vec operations
+    /// Union this `BitSet` with `other` in place: every bit set in either
+    /// `self` or `other` ends up set in `self`. If `other` holds more bits
+    /// than `self`, `self` is grown to match.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitset::BitSet;
+    ///
+    /// let mut a = BitSet::with_capacity(8);
+    /// let b = BitSet::from_u64(0b101);
+    /// a.union_with(&b);
+    /// assert!(a.test(0) == true);
+    /// assert!(a.test(2) == true);
+    /// ```
+    pub fn union_with(&mut self, other: &BitSet) {
+        if other.blocks() > self.bits.len() {
+            self.bits.resize(other.blocks(), 0);
+        }
+        if other.nbits > self.nbits {
+            self.nbits = other.nbits;
+        }
+        for i in 0..other.blocks() {
+            self.bits[i] |= other.bits[i];
+        }
+    }
+
+    /// Intersect this `BitSet` with `other` in place: a bit stays set in
+    /// `self` only if it is also set in `other`. Bits beyond the end of
+    /// `other` are treated as `0` and so are cleared in `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitset::BitSet;
+    ///
+    /// let mut a = BitSet::from_u64(0b111);
+    /// let b = BitSet::from_u64(0b101);
+    /// a.intersect_with(&b);
+    /// assert!(a.test(0) == true);
+    /// assert!(a.test(1) == false);
+    /// assert!(a.test(2) == true);
+    /// ```
+    pub fn intersect_with(&mut self, other: &BitSet) {
+        let common = other.blocks().min(self.bits.len());
+        for i in 0..common {
+            self.bits[i] &= other.bits[i];
+        }
+        for i in common..self.bits.len() {
+            self.bits[i] = 0;
+        }
+        self.mask_tail();
+    }
+
+    /// Remove from this `BitSet` every bit that is also set in `other`.
+    /// Bits beyond the end of `other` are treated as `0` and so are left
+    /// untouched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitset::BitSet;
+    ///
+    /// let mut a = BitSet::from_u64(0b111);
+    /// let b = BitSet::from_u64(0b101);
+    /// a.difference_with(&b);
+    /// assert!(a.test(0) == false);
+    /// assert!(a.test(1) == true);
+    /// assert!(a.test(2) == false);
+    /// ```
+    pub fn difference_with(&mut self, other: &BitSet) {
+        let common = other.blocks().min(self.bits.len());
+        for i in 0..common {
+            self.bits[i] &= !other.bits[i];
+        }
+        self.mask_tail();
+    }
+
+    /// Symmetric-difference this `BitSet` with `other` in place: a bit ends
+    /// up set in `self` iff it was set in exactly one of `self`/`other`. If
+    /// `other` holds more bits than `self`, `self` is grown to match.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitset::BitSet;
+    ///
+    /// let mut a = BitSet::from_u64(0b110);
+    /// let b = BitSet::from_u64(0b101);
+    /// a.symmetric_difference_with(&b);
+    /// assert!(a.test(0) == true);
+    /// assert!(a.test(1) == true);
+    /// assert!(a.test(2) == false);
+    /// ```
+    pub fn symmetric_difference_with(&mut self, other: &BitSet) {
+        if other.blocks() > self.bits.len() {
+            self.bits.resize(other.blocks(), 0);
+        }
+        if other.nbits > self.nbits {
+            self.nbits = other.nbits;
+        }
+        for i in 0..other.blocks() {
+            self.bits[i] ^= other.bits[i];
+        }
+    }
+
+    /// Return a new `BitSet` holding the union of `self` and `other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitset::BitSet;
+    ///
+    /// let a = BitSet::from_u64(0b100);
+    /// let b = BitSet::from_u64(0b001);
+    /// let c = a.union(&b);
+    /// assert!(c.test(0) == true);
+    /// assert!(c.test(2) == true);
+    /// ```
+    pub fn union(&self, other: &BitSet) -> BitSet {
+        let mut result = self.clone();
+        result.union_with(other);
+        result
+    }
+
+    /// Return a new `BitSet` holding the intersection of `self` and `other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitset::BitSet;
+    ///
+    /// let a = BitSet::from_u64(0b110);
+    /// let b = BitSet::from_u64(0b101);
+    /// let c = a.intersection(&b);
+    /// assert!(c.test(2) == true);
+    /// assert!(c.test(1) == false);
+    /// ```
+    pub fn intersection(&self, other: &BitSet) -> BitSet {
+        let mut result = self.clone();
+        result.intersect_with(other);
+        result
+    }
 
     // bit operations
     /// Set the bit specified by `bit_idx` to `v`, which is `true` or `false`.
+    /// If `bit_idx` is beyond the current [`size`](BitSet::size), and `v` is
+    /// `true`, the `BitSet` grows to make room for it instead of doing
+    /// nothing.
     ///
     /// # Arguments
     ///
@@ -219,8 +685,15 @@ impl BitSet {
     /// let mut bs = BitSet::with_capacity(100);
     /// bs.set(99, true);
     /// assert!(bs.test(99) == true);
+    ///
+    /// let mut grown = BitSet::new();
+    /// grown.set(99, true);
+    /// assert!(grown.size() == 100);
     /// ```
     pub fn set(&mut self, bit_idx: usize, v: bool) {
+        if v {
+            self.ensure_capacity(bit_idx);
+        }
         let (block_idx, mod_bit_idx) = (bit_idx / 64, bit_idx % 64);
         if let Some(n) = self.bits.get_mut(block_idx) {
             if v {
@@ -232,6 +705,71 @@ impl BitSet {
         }
     }
 
+    /// Insert `bit_idx` into the set, growing the `BitSet` to make room for
+    /// it if necessary. Returns `true` if the bit was not already set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitset::BitSet;
+    ///
+    /// let mut bs = BitSet::new();
+    /// assert!(bs.insert(99) == true);
+    /// assert!(bs.insert(99) == false);
+    /// assert!(bs.test(99) == true);
+    /// ```
+    pub fn insert(&mut self, bit_idx: usize) -> bool {
+        let was_set = bit_idx < self.nbits && self.test(bit_idx);
+        self.set(bit_idx, true);
+        !was_set
+    }
+
+    /// Remove `bit_idx` from the set. Returns `true` if the bit was set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitset::BitSet;
+    ///
+    /// let mut bs = BitSet::with_capacity(100);
+    /// bs.insert(99);
+    /// assert!(bs.remove(99) == true);
+    /// assert!(bs.test(99) == false);
+    /// assert!(bs.remove(99) == false);
+    /// ```
+    pub fn remove(&mut self, bit_idx: usize) -> bool {
+        let was_set = bit_idx < self.nbits && self.test(bit_idx);
+        if was_set {
+            self.set(bit_idx, false);
+        }
+        was_set
+    }
+
+    /// Pre-allocate space for `additional` more bits beyond the current
+    /// [`size`](BitSet::size), without changing `size` itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitset::BitSet;
+    ///
+    /// let mut bs = BitSet::new();
+    /// bs.reserve(128);
+    /// assert!(bs.size() == 0);
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        let total_bits = self.nbits + additional;
+        let total_blocks = if total_bits % 64 == 0 {
+            total_bits / 64
+        }
+        else {
+            total_bits / 64 + 1
+        };
+        if total_blocks > self.bits.len() {
+            self.bits.reserve(total_blocks - self.bits.len());
+        }
+    }
+
     /// Reset all bits to `0`.
     ///
     /// # Example
@@ -305,6 +843,47 @@ impl BitSet {
     }
 }
 
+impl core::ops::BitAnd for &BitSet {
+    type Output = BitSet;
+
+    /// `&a & &b` returns the intersection of `a` and `b`.
+    fn bitand(self, other: &BitSet) -> BitSet {
+        self.intersection(other)
+    }
+}
+
+impl core::ops::BitOr for &BitSet {
+    type Output = BitSet;
+
+    /// `&a | &b` returns the union of `a` and `b`.
+    fn bitor(self, other: &BitSet) -> BitSet {
+        self.union(other)
+    }
+}
+
+impl core::ops::BitXor for &BitSet {
+    type Output = BitSet;
+
+    /// `&a ^ &b` returns the symmetric difference of `a` and `b`.
+    fn bitxor(self, other: &BitSet) -> BitSet {
+        let mut result = self.clone();
+        result.symmetric_difference_with(other);
+        result
+    }
+}
+
+impl core::ops::Not for &BitSet {
+    type Output = BitSet;
+
+    /// `!&a` returns a copy of `a` with every bit flipped.
+    fn not(self) -> BitSet {
+        let mut result = self.clone();
+        result.flip_all();
+        result.mask_tail();
+        result
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -370,4 +949,198 @@ mod tests {
             assert!(bitset.test(i) == false);
         }
     }
+
+    #[test]
+    fn test_union_with() {
+        let mut a = BitSet::from_u64(0b100);
+        let b = BitSet::from_u64(0b001);
+        a.union_with(&b);
+        assert!(a.test(0) == true);
+        assert!(a.test(1) == false);
+        assert!(a.test(2) == true);
+
+        // growing: other has more blocks than self
+        let mut c = BitSet::from_u64(0b1);
+        let d = BitSet::from_vec64(&vec![0, 0b1]);
+        c.union_with(&d);
+        assert!(c.size() == 128);
+        assert!(c.test(0) == true);
+        assert!(c.test(64) == true);
+    }
+
+    #[test]
+    fn test_intersect_with() {
+        let mut a = BitSet::from_u64(0b110);
+        let b = BitSet::from_u64(0b101);
+        a.intersect_with(&b);
+        assert!(a.test(1) == false);
+        assert!(a.test(2) == true);
+        assert!(a.count() == 1);
+
+        // other shorter than self: missing words treated as zero
+        let mut c = BitSet::from_vec64(&vec![0b1, 0b1]);
+        let d = BitSet::from_u64(0b1);
+        c.intersect_with(&d);
+        assert!(c.count() == 1);
+    }
+
+    #[test]
+    fn test_difference_with() {
+        let mut a = BitSet::from_u64(0b111);
+        let b = BitSet::from_u64(0b101);
+        a.difference_with(&b);
+        assert!(a.test(0) == false);
+        assert!(a.test(1) == true);
+        assert!(a.test(2) == false);
+    }
+
+    #[test]
+    fn test_symmetric_difference_with() {
+        let mut a = BitSet::from_u64(0b110);
+        let b = BitSet::from_u64(0b101);
+        a.symmetric_difference_with(&b);
+        assert!(a.test(0) == true);
+        assert!(a.test(1) == true);
+        assert!(a.test(2) == false);
+    }
+
+    #[test]
+    fn test_union_intersection() {
+        let a = BitSet::from_u64(0b110);
+        let b = BitSet::from_u64(0b101);
+        assert!(a.union(&b).count() == 3);
+        assert!(a.intersection(&b).count() == 1);
+    }
+
+    #[test]
+    fn test_bit_operators() {
+        let a = BitSet::from_u64(0b110);
+        let b = BitSet::from_u64(0b101);
+        assert!((&a & &b).count() == 1);
+        assert!((&a | &b).count() == 3);
+        assert!((&a ^ &b).count() == 2);
+        assert!((!&a).test(0) == true);
+        assert!((!&a).test(1) == false);
+    }
+
+    #[test]
+    fn test_not_masks_tail() {
+        let mut a = BitSet::with_capacity(100);
+        a.set(0, true);
+        let negated = !&a;
+        assert!(negated.count() == 99);
+        assert!(negated.test(127) == false);
+    }
+
+    #[test]
+    fn test_iter() {
+        let bs = BitSet::from_vec64(&vec![0b1010, 0b1]);
+        let members: Vec<usize> = bs.iter().collect();
+        assert!(members == vec![1, 3, 64]);
+
+        let empty = BitSet::with_capacity(64);
+        assert!(empty.iter().next() == None);
+    }
+
+    #[test]
+    fn test_from_iterator_and_extend() {
+        let bs: BitSet = vec![1usize, 3, 130].into_iter().collect();
+        assert!(bs.test(1) == true);
+        assert!(bs.test(3) == true);
+        assert!(bs.test(130) == true);
+        assert!(bs.size() == 131);
+
+        let mut bs2 = BitSet::new();
+        bs2.extend(vec![0usize, 5]);
+        assert!(bs2.count() == 2);
+    }
+
+    #[test]
+    fn test_set_grows() {
+        let mut bs = BitSet::new();
+        assert!(bs.size() == 0);
+        bs.set(130, true);
+        assert!(bs.size() == 131);
+        assert!(bs.test(130) == true);
+    }
+
+    #[test]
+    fn test_insert_remove() {
+        let mut bs = BitSet::new();
+        assert!(bs.insert(99) == true);
+        assert!(bs.insert(99) == false);
+        assert!(bs.test(99) == true);
+        assert!(bs.remove(99) == true);
+        assert!(bs.test(99) == false);
+        assert!(bs.remove(99) == false);
+    }
+
+    #[test]
+    fn test_reserve() {
+        let mut bs = BitSet::new();
+        bs.reserve(128);
+        assert!(bs.size() == 0);
+        assert!(bs.count() == 0);
+    }
+
+    #[test]
+    fn test_from_bytes_to_bytes_roundtrip() {
+        let bs = BitSet::from_bytes(&[0b11010000, 0b00000001]);
+        assert!(bs.test(0) == true);
+        assert!(bs.test(1) == true);
+        assert!(bs.test(2) == false);
+        assert!(bs.test(3) == true);
+        assert!(bs.test(15) == true);
+        assert!(bs.to_bytes() == vec![0b11010000, 0b00000001]);
+    }
+
+    #[test]
+    fn test_as_slice_into_vec64() {
+        let bs = BitSet::from_vec64(&vec![0b101, 0b10]);
+        assert!(bs.as_slice() == &[0b101, 0b10]);
+        assert!(bs.into_vec64() == vec![0b101, 0b10]);
+    }
+
+    #[test]
+    fn test_find_first_find_next() {
+        let bs = BitSet::from_vec64(&vec![0, 0b1000]);
+        assert!(bs.find_first() == Some(67));
+        assert!(bs.find_next(0) == Some(67));
+        assert!(bs.find_next(68) == None);
+
+        let empty = BitSet::with_capacity(64);
+        assert!(empty.find_first() == None);
+    }
+
+    #[test]
+    fn test_count_range() {
+        let bs = BitSet::from_vec64(&vec![u64::max_value(), 0b11]);
+        assert!(bs.count_range(0, 64) == 64);
+        assert!(bs.count_range(60, 68) == 6);
+        assert!(bs.count_range(64, 66) == 2);
+        assert!(bs.count_range(64, 128) == 2);
+    }
+
+    #[test]
+    fn test_set_range() {
+        let mut bs = BitSet::with_capacity(8);
+        bs.set_range(2, 6, true);
+        assert!(bs.count() == 4);
+        assert!(bs.test(1) == false);
+        assert!(bs.test(2) == true);
+        assert!(bs.test(5) == true);
+        assert!(bs.test(6) == false);
+
+        bs.set_range(3, 5, false);
+        assert!(bs.test(2) == true);
+        assert!(bs.test(3) == false);
+        assert!(bs.test(4) == false);
+        assert!(bs.test(5) == true);
+
+        // growing: set_range past current size
+        let mut grown = BitSet::new();
+        grown.set_range(60, 70, true);
+        assert!(grown.size() == 70);
+        assert!(grown.count_range(60, 70) == 10);
+    }
 }